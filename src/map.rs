@@ -0,0 +1,853 @@
+use std::borrow::Borrow;
+use std::collections::TryReserveError;
+use std::hash::{BuildHasher, Hash};
+use std::mem;
+
+use crate::RandomState;
+
+struct Elem<K, V> {
+    key: K,
+    value: V,
+    psl: u8,
+}
+
+pub struct HashMap<K, V, S = RandomState> {
+    buffer: Vec<Option<Elem<K, V>>>,
+    capacity: usize,
+    hash_builder: S,
+    len: usize,
+}
+
+impl<K, V> HashMap<K, V, RandomState>
+where
+    K: Hash + Eq,
+{
+    /// Creates an empty map with the default capacity, hashed with a
+    /// randomly seeded [`RandomState`] so that different maps don't share a
+    /// probe sequence.
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+
+    /// Creates an empty map that can hold at least `capacity` entries
+    /// without resizing, hashed with a randomly seeded [`RandomState`].
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K, V> Default for HashMap<K, V, RandomState>
+where
+    K: Hash + Eq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, S> HashMap<K, V, S>
+where
+    S: BuildHasher,
+    K: Hash + Eq,
+{
+    const DEFAULT_SIZE: usize = 256;
+    const RESIZE_THRESHOLD: f64 = 0.8;
+    const RESIZE_FACTOR: usize = 2;
+
+    /// Creates an empty map with the default capacity, hashed with `hash_builder`.
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self::with_capacity_and_hasher(Self::DEFAULT_SIZE, hash_builder)
+    }
+
+    /// Creates an empty map that can hold at least `capacity` entries
+    /// without resizing, hashed with `hash_builder`.
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        Self {
+            buffer: (0..capacity).map(|_| None).collect(),
+            capacity,
+            hash_builder,
+            len: 0,
+        }
+    }
+
+    fn hash<Q>(&self, key: &Q) -> u32
+    where
+        Q: Hash + ?Sized,
+    {
+        self.hash_builder.hash_one(key) as u32
+    }
+
+    /// Returns the (index, psl) for insertion/lookup. The index may point to:
+    ///     1. Empty slot - key absent
+    ///     2. Matching key - key found
+    ///     3. Wrong key - PSL exceeded, key absent
+    /// Callers must verify key equality for case 3. The returned `psl` is the
+    /// probe distance from the key's home slot, needed to keep the Robin
+    /// Hood invariant intact when inserting at that index.
+    fn find_elem<Q>(&self, key: &Q) -> (usize, u8)
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let hash = self.hash(key);
+        let mut psl = 0;
+        let mut index = (hash as usize) % self.capacity;
+
+        loop {
+            match &self.buffer[index] {
+                None => return (index, psl),
+                Some(elem) if psl > elem.psl || elem.key.borrow() == key => {
+                    return (index, psl);
+                }
+                _ => {
+                    index = (index + 1) % self.capacity;
+                    psl += 1;
+                }
+            }
+        }
+    }
+
+    /// Runs the Robin Hood swap-displacement loop starting at `index` with
+    /// `new.psl` as given, and returns the index `new`'s key ends up at.
+    ///
+    /// Callers must already know `new.key` is absent from the map.
+    fn displace_insert(&mut self, mut new: Elem<K, V>, mut index: usize) -> usize {
+        let mut landed_at = None;
+
+        loop {
+            match &mut self.buffer[index] {
+                None => {
+                    self.buffer[index] = Some(new);
+                    self.len += 1;
+                    return landed_at.unwrap_or(index);
+                }
+                Some(existing) => {
+                    if new.psl > existing.psl {
+                        mem::swap(&mut new, existing);
+                        landed_at.get_or_insert(index);
+                    }
+                }
+            }
+
+            index = (index + 1) % self.capacity;
+            new.psl += 1;
+        }
+    }
+
+    /// Gets the entry for `key` in the map for in-place updates.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        if self.len >= (self.capacity as f64 * Self::RESIZE_THRESHOLD) as usize {
+            self.resize();
+        }
+
+        let (index, psl) = self.find_elem(&key);
+
+        match &self.buffer[index] {
+            Some(elem) if elem.key == key => Entry::Occupied(OccupiedEntry { map: self, index }),
+            _ => Entry::Vacant(VacantEntry {
+                map: self,
+                key,
+                index,
+                psl,
+            }),
+        }
+    }
+
+    fn resize(&mut self) {
+        self.try_resize_to(self.capacity * Self::RESIZE_FACTOR)
+            .expect("allocation failure while resizing");
+    }
+
+    /// Grows the backing buffer to `new_capacity` and rehashes every entry
+    /// into it. The old buffer is left untouched if the allocation fails.
+    fn try_resize_to(&mut self, new_capacity: usize) -> Result<(), TryReserveError> {
+        let mut new_buffer = Vec::new();
+        new_buffer.try_reserve(new_capacity)?;
+        new_buffer.resize_with(new_capacity, || None);
+
+        let org_buffer = mem::replace(&mut self.buffer, new_buffer);
+        self.capacity = new_capacity;
+        self.len = 0;
+
+        for elem in org_buffer.into_iter().flatten() {
+            let (index, psl) = self.find_elem(&elem.key);
+            self.displace_insert(Elem { psl, ..elem }, index);
+        }
+
+        Ok(())
+    }
+
+    /// Reserves capacity for at least `additional` more entries, growing the
+    /// backing buffer in [`Self::RESIZE_FACTOR`] steps until `len +
+    /// additional` stays below [`Self::RESIZE_THRESHOLD`]. Returns an error
+    /// instead of aborting if the allocation can't be satisfied.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self.len + additional;
+        let mut target_capacity = self.capacity;
+
+        while required >= (target_capacity as f64 * Self::RESIZE_THRESHOLD) as usize {
+            target_capacity *= Self::RESIZE_FACTOR;
+        }
+
+        if target_capacity == self.capacity {
+            return Ok(());
+        }
+
+        self.try_resize_to(target_capacity)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        self.try_insert(key, value)
+            .expect("allocation failure while inserting");
+    }
+
+    /// Like [`Self::insert`], but returns an error instead of aborting if
+    /// growing the backing buffer fails.
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<(), TryReserveError> {
+        if self.len >= (self.capacity as f64 * Self::RESIZE_THRESHOLD) as usize {
+            self.try_reserve(1)?;
+        }
+
+        let (index, psl) = self.find_elem(&key);
+
+        match &mut self.buffer[index] {
+            Some(elem) if elem.key == key => elem.value = value,
+            _ => {
+                self.displace_insert(Elem { key, value, psl }, index);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (index, _) = self.find_elem(key);
+
+        match &self.buffer[index] {
+            Some(elem) if elem.key.borrow() == key => Some(&elem.value),
+            _ => None,
+        }
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (index, _) = self.find_elem(key);
+
+        match &mut self.buffer[index] {
+            Some(elem) if elem.key.borrow() == key => Some(&mut elem.value),
+            _ => None,
+        }
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (mut index, _) = self.find_elem(key);
+
+        match &self.buffer[index] {
+            Some(elem) if elem.key.borrow() == key => {}
+            _ => return None,
+        }
+
+        let removed = self.buffer[index].take().unwrap().value;
+        self.len -= 1;
+
+        index = (index + 1) % self.capacity;
+
+        // backward shift elements belonging to current bucket
+        while let Some(elem) = &mut self.buffer[index] {
+            if elem.psl == 0 {
+                break;
+            }
+
+            elem.psl -= 1;
+            let prev = (index + self.capacity - 1) % self.capacity;
+            self.buffer[prev] = self.buffer[index].take();
+            index = (index + 1) % self.capacity;
+        }
+
+        Some(removed)
+    }
+
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: self.buffer.iter(),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            inner: self.buffer.iter_mut(),
+        }
+    }
+
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { inner: self.iter() }
+    }
+
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { inner: self.iter() }
+    }
+
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut {
+            inner: self.iter_mut(),
+        }
+    }
+}
+
+impl<K, V, S> IntoIterator for HashMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: self.buffer.into_iter(),
+        }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a HashMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            inner: self.buffer.iter(),
+        }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut HashMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut {
+            inner: self.buffer.iter_mut(),
+        }
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::with_hasher(S::default());
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+/// An iterator over the entries of a [`HashMap`], skipping empty slots.
+pub struct Iter<'a, K, V> {
+    inner: std::slice::Iter<'a, Option<Elem<K, V>>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let elem = self.inner.by_ref().flatten().next()?;
+        Some((&elem.key, &elem.value))
+    }
+}
+
+/// A mutable iterator over the entries of a [`HashMap`], skipping empty slots.
+pub struct IterMut<'a, K, V> {
+    inner: std::slice::IterMut<'a, Option<Elem<K, V>>>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let elem = self.inner.by_ref().flatten().next()?;
+        Some((&elem.key, &mut elem.value))
+    }
+}
+
+/// An owning iterator over the entries of a [`HashMap`], skipping empty slots.
+pub struct IntoIter<K, V> {
+    inner: std::vec::IntoIter<Option<Elem<K, V>>>,
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let elem = self.inner.by_ref().flatten().next()?;
+        Some((elem.key, elem.value))
+    }
+}
+
+/// An iterator over the keys of a [`HashMap`].
+pub struct Keys<'a, K, V> {
+    inner: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, _)| key)
+    }
+}
+
+/// An iterator over the values of a [`HashMap`].
+pub struct Values<'a, K, V> {
+    inner: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+}
+
+/// A mutable iterator over the values of a [`HashMap`].
+pub struct ValuesMut<'a, K, V> {
+    inner: IterMut<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, value)| value)
+    }
+}
+
+/// A view into a single entry in a map, which may either be vacant or occupied.
+pub enum Entry<'a, K, V, S> {
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    Vacant(VacantEntry<'a, K, V, S>),
+}
+
+impl<'a, K, V, S> Entry<'a, K, V, S>
+where
+    S: BuildHasher,
+    K: Hash + Eq,
+{
+    /// Ensures a value is in the entry by inserting `default` if vacant, and
+    /// returns a mutable reference to the value.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Ensures a value is in the entry by inserting the result of `default`
+    /// if vacant, and returns a mutable reference to the value.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential insert.
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+/// A view into an occupied entry in a map.
+pub struct OccupiedEntry<'a, K, V, S> {
+    map: &'a mut HashMap<K, V, S>,
+    index: usize,
+}
+
+impl<'a, K, V, S> OccupiedEntry<'a, K, V, S>
+where
+    S: BuildHasher,
+    K: Hash + Eq,
+{
+    /// Returns a reference to the entry's value.
+    pub fn get(&self) -> &V {
+        &self.map.buffer[self.index].as_ref().unwrap().value
+    }
+
+    /// Returns a mutable reference to the entry's value.
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map.buffer[self.index].as_mut().unwrap().value
+    }
+
+    /// Converts the entry into a mutable reference to its value, bound to
+    /// the lifetime of the map.
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.buffer[self.index].as_mut().unwrap().value
+    }
+
+    /// Replaces the entry's value, returning the old one.
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+}
+
+/// A view into a vacant entry in a map, ready to be filled via [`insert`](VacantEntry::insert).
+pub struct VacantEntry<'a, K, V, S> {
+    map: &'a mut HashMap<K, V, S>,
+    key: K,
+    index: usize,
+    psl: u8,
+}
+
+impl<'a, K, V, S> VacantEntry<'a, K, V, S>
+where
+    S: BuildHasher,
+    K: Hash + Eq,
+{
+    /// Sets the value of the entry, returning a mutable reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        let elem = Elem {
+            key: self.key,
+            value,
+            psl: self.psl,
+        };
+        let index = self.map.displace_insert(elem, self.index);
+
+        &mut self.map.buffer[index].as_mut().unwrap().value
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::BuildHasherDefault;
+
+    fn hasher() -> BuildHasherDefault<DefaultHasher> {
+        BuildHasherDefault::default()
+    }
+
+    #[test]
+    fn test_default_creation() {
+        let map: HashMap<String, String, _> = HashMap::with_hasher(hasher());
+
+        assert_eq!(map.capacity, 256);
+    }
+
+    #[test]
+    fn test_with_capacity_creation() {
+        let map: HashMap<String, String, _> = HashMap::with_capacity_and_hasher(100, hasher());
+
+        assert_eq!(map.capacity, 100);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+
+        map.insert("Hello,", "World");
+    }
+
+    #[test]
+    fn test_insert_overwrite() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+
+        map.insert("Hello,", "World");
+        map.insert("Hello,", "Me");
+
+        assert_eq!("Me", *map.get("Hello,").unwrap());
+        assert_eq!(1, map.len);
+        assert!(
+            map.buffer
+                .iter()
+                .filter_map(|elem| elem.as_ref())
+                .all(|elem| elem.value == "Me")
+        );
+    }
+
+    #[test]
+    fn test_insert_overwrite_removed() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+
+        map.insert("Hello,", "World");
+        map.remove("Hello,");
+        map.insert("Hello,", "Me");
+
+        assert_eq!("Me", *map.get("Hello,").unwrap());
+        assert_eq!(1, map.len);
+        assert!(
+            map.buffer
+                .iter()
+                .filter_map(|elem| elem.as_ref())
+                .all(|elem| elem.value == "Me")
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+
+        map.insert("Hello,", "World");
+
+        assert_eq!("World", *map.get("Hello,").unwrap());
+        assert_eq!(None, map.get("Hi,"));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+
+        map.insert("Hello,", "World");
+
+        assert_eq!(Some("World"), map.remove("Hello,"));
+        assert_eq!(None, map.remove("Hello,"));
+        assert_eq!(None, map.get("Hello,"));
+        assert!(
+            !map.buffer
+                .iter()
+                .filter_map(|elem| elem.as_ref())
+                .any(|elem| elem.key == "Hello,")
+        );
+    }
+
+    #[test]
+    fn test_remove_backward_shift_wraps_past_zero() {
+        let capacity = 4;
+        let home = |key: i32| (hasher().hash_one(key) as u32 as usize) % capacity;
+
+        // Two keys whose home slot is the last index, so the second one
+        // wraps around into slot 0 when it's inserted.
+        let mut colliding = (0..10_000).filter(|&key| home(key) == capacity - 1);
+        let first = colliding.next().expect("a key hashing to the last slot");
+        let second = colliding.next().expect("a second key hashing to the last slot");
+
+        let mut map = HashMap::with_capacity_and_hasher(capacity, hasher());
+        map.insert(first, "a");
+        map.insert(second, "b");
+
+        assert_eq!(1, map.buffer[0].as_ref().unwrap().psl);
+
+        assert_eq!(Some("a"), map.remove(&first));
+
+        assert_eq!(Some(&"b"), map.get(&second));
+        assert!(map.buffer[0].is_none());
+        assert_eq!(0, map.buffer[capacity - 1].as_ref().unwrap().psl);
+    }
+
+    #[test]
+    fn test_get_borrowed_key() {
+        let mut map: HashMap<String, i32, _> = HashMap::with_capacity_and_hasher(16, hasher());
+
+        map.insert(String::from("Hello,"), 1);
+
+        assert_eq!(Some(1), map.get("Hello,").copied());
+    }
+
+    #[test]
+    fn test_resize() {
+        let size = 10;
+        let mut map = HashMap::with_capacity_and_hasher(size, hasher());
+
+        for i in 0..size {
+            map.insert(i, "number");
+        }
+
+        for i in 0..size {
+            assert_eq!(Some("number"), map.get(&i).copied());
+        }
+
+        assert_eq!(size * 2, map.buffer.len());
+        assert_eq!(size * 2, map.capacity);
+        assert_eq!(size, map.len);
+        assert_eq!(map.capacity, map.buffer.len(),);
+    }
+
+    #[test]
+    fn test_try_reserve_grows_capacity() {
+        let mut map: HashMap<i32, &str, _> = HashMap::with_capacity_and_hasher(10, hasher());
+
+        assert!(map.try_reserve(9).is_ok());
+
+        assert_eq!(20, map.capacity);
+        assert_eq!(20, map.buffer.len());
+    }
+
+    #[test]
+    fn test_try_reserve_noop_when_capacity_suffices() {
+        let mut map: HashMap<i32, &str, _> = HashMap::with_capacity_and_hasher(10, hasher());
+
+        assert!(map.try_reserve(1).is_ok());
+
+        assert_eq!(10, map.capacity);
+    }
+
+    #[test]
+    fn test_try_insert() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+
+        assert!(map.try_insert("Hello,", "World").is_ok());
+        assert_eq!("World", *map.get("Hello,").unwrap());
+    }
+
+    #[test]
+    fn test_entry_or_insert_vacant() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+
+        *map.entry("count").or_insert(0) += 1;
+
+        assert_eq!(Some(1), map.get("count").copied());
+    }
+
+    #[test]
+    fn test_entry_or_insert_occupied() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+
+        map.insert("count", 41);
+        *map.entry("count").or_insert(0) += 1;
+
+        assert_eq!(Some(42), map.get("count").copied());
+    }
+
+    #[test]
+    fn test_entry_and_modify() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+
+        map.insert("count", 1);
+        map.entry("count").and_modify(|v| *v += 1);
+        map.entry("missing").and_modify(|v| *v += 1);
+
+        assert_eq!(Some(2), map.get("count").copied());
+        assert_eq!(None, map.get("missing"));
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+
+        map.insert("Hello,", "World");
+
+        if let Some(value) = map.get_mut("Hello,") {
+            *value = "Me";
+        }
+
+        assert_eq!("Me", *map.get("Hello,").unwrap());
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+
+        let mut entries: Vec<_> = map.iter().collect();
+        entries.sort_unstable();
+
+        assert_eq!(vec![(&"a", &1), (&"b", &2)], entries);
+    }
+
+    #[test]
+    fn test_keys_and_values() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+
+        let mut keys: Vec<_> = map.keys().collect();
+        keys.sort_unstable();
+        let mut values: Vec<_> = map.values().collect();
+        values.sort_unstable();
+
+        assert_eq!(vec![&"a", &"b"], keys);
+        assert_eq!(vec![&1, &2], values);
+    }
+
+    #[test]
+    fn test_values_mut() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+
+        map.insert("a", 1);
+        map.insert("b", 2);
+
+        for value in map.values_mut() {
+            *value *= 10;
+        }
+
+        let mut values: Vec<_> = map.values().collect();
+        values.sort_unstable();
+
+        assert_eq!(vec![&10, &20], values);
+    }
+
+    #[test]
+    fn test_into_iterator_by_ref() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+
+        map.insert("a", 1);
+
+        let mut entries: Vec<_> = (&map).into_iter().collect();
+        entries.sort_unstable();
+
+        assert_eq!(vec![(&"a", &1)], entries);
+        assert_eq!(Some(1), map.get("a").copied());
+    }
+
+    #[test]
+    fn test_into_iterator_owned() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+
+        map.insert("a", 1);
+
+        let mut entries: Vec<_> = map.into_iter().collect();
+        entries.sort_unstable();
+
+        assert_eq!(vec![("a", 1)], entries);
+    }
+
+    #[test]
+    fn test_from_iterator() {
+        let pairs = vec![("a", 1), ("b", 2)];
+
+        let map: HashMap<&str, i32, BuildHasherDefault<DefaultHasher>> = pairs.into_iter().collect();
+
+        assert_eq!(Some(&1), map.get("a"));
+        assert_eq!(Some(&2), map.get("b"));
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut map = HashMap::with_capacity_and_hasher(16, hasher());
+        map.insert("a", 1);
+
+        map.extend(vec![("b", 2), ("c", 3)]);
+
+        assert_eq!(Some(&1), map.get("a"));
+        assert_eq!(Some(&2), map.get("b"));
+        assert_eq!(Some(&3), map.get("c"));
+    }
+}
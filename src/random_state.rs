@@ -0,0 +1,230 @@
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Derives a fresh pair of SipHash keys on every call, mixing a value that's
+/// fixed for the lifetime of the process with a process-wide counter shared
+/// by every thread. Sharing the counter (rather than keeping it per-thread)
+/// is what keeps two [`RandomState`]s from ever hashing identically, even
+/// when constructed concurrently on different threads.
+fn next_keys() -> (u64, u64) {
+    static NONCE: OnceLock<u64> = OnceLock::new();
+    let nonce = *NONCE.get_or_init(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0)
+            ^ (&NONCE as *const _ as u64)
+    });
+
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+
+    let k0 = nonce ^ count.wrapping_mul(0x9e3779b97f4a7c15);
+    let k1 = nonce.rotate_left(32) ^ count.wrapping_add(0x517cc1b727220a95);
+
+    (k0, k1)
+}
+
+/// A [`BuildHasher`] that seeds a SipHash-1-3 keyed hasher with random keys
+/// on construction, so that two [`HashMap`](crate::HashMap)s hash their
+/// entries differently. Without this, an adversary who can choose the keys
+/// fed to a map could force worst-case Robin Hood probe chains (a HashDoS).
+///
+/// Construct with [`RandomState::new`] or [`Default::default`]. For
+/// reproducible hashing (e.g. in tests), use a fixed `BuildHasher` such as
+/// `BuildHasherDefault<DefaultHasher>` instead.
+#[derive(Clone)]
+pub struct RandomState {
+    k0: u64,
+    k1: u64,
+}
+
+impl RandomState {
+    /// Creates a new `RandomState` with freshly derived keys.
+    pub fn new() -> Self {
+        let (k0, k1) = next_keys();
+        Self { k0, k1 }
+    }
+}
+
+impl Default for RandomState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for RandomState {
+    type Hasher = SipHasher13;
+
+    fn build_hasher(&self) -> SipHasher13 {
+        SipHasher13::new_with_keys(self.k0, self.k1)
+    }
+}
+
+/// One round of the SipHash ARX mixing function.
+fn sip_round(v0: &mut u64, v1: &mut u64, v2: &mut u64, v3: &mut u64) {
+    *v0 = v0.wrapping_add(*v1);
+    *v1 = v1.rotate_left(13);
+    *v1 ^= *v0;
+    *v0 = v0.rotate_left(32);
+
+    *v2 = v2.wrapping_add(*v3);
+    *v3 = v3.rotate_left(16);
+    *v3 ^= *v2;
+
+    *v0 = v0.wrapping_add(*v3);
+    *v3 = v3.rotate_left(21);
+    *v3 ^= *v0;
+
+    *v2 = v2.wrapping_add(*v1);
+    *v1 = v1.rotate_left(17);
+    *v1 ^= *v2;
+    *v2 = v2.rotate_left(32);
+}
+
+/// A SipHash-1-3 hasher (one compression round per word, three finalization
+/// rounds), keyed with a pair of 64-bit secrets.
+pub struct SipHasher13 {
+    v0: u64,
+    v1: u64,
+    v2: u64,
+    v3: u64,
+    // Bytes not yet long enough to form a full word, buffered little-endian
+    // in the low `tail_len` bytes.
+    tail: u64,
+    tail_len: u8,
+    length: u64,
+}
+
+impl SipHasher13 {
+    fn new_with_keys(k0: u64, k1: u64) -> Self {
+        Self {
+            v0: k0 ^ 0x736f_6d65_7073_6575,
+            v1: k1 ^ 0x646f_7261_6e64_6f6d,
+            v2: k0 ^ 0x6c79_6765_6e65_7261,
+            v3: k1 ^ 0x7465_6462_7974_6573,
+            tail: 0,
+            tail_len: 0,
+            length: 0,
+        }
+    }
+
+    fn process_word(&mut self, word: u64) {
+        self.v3 ^= word;
+        sip_round(&mut self.v0, &mut self.v1, &mut self.v2, &mut self.v3);
+        self.v0 ^= word;
+    }
+}
+
+impl Hasher for SipHasher13 {
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.length = self.length.wrapping_add(bytes.len() as u64);
+
+        if self.tail_len > 0 {
+            let needed = 8 - self.tail_len as usize;
+            let take = needed.min(bytes.len());
+
+            for (i, &byte) in bytes[..take].iter().enumerate() {
+                self.tail |= (byte as u64) << (8 * (self.tail_len as usize + i));
+            }
+            self.tail_len += take as u8;
+            bytes = &bytes[take..];
+
+            if self.tail_len < 8 {
+                return;
+            }
+
+            let word = self.tail;
+            self.process_word(word);
+            self.tail = 0;
+            self.tail_len = 0;
+        }
+
+        while bytes.len() >= 8 {
+            let word = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+            self.process_word(word);
+            bytes = &bytes[8..];
+        }
+
+        for (i, &byte) in bytes.iter().enumerate() {
+            self.tail |= (byte as u64) << (8 * i);
+        }
+        self.tail_len = bytes.len() as u8;
+    }
+
+    fn finish(&self) -> u64 {
+        let mut v0 = self.v0;
+        let mut v1 = self.v1;
+        let mut v2 = self.v2;
+        let mut v3 = self.v3;
+
+        let last_word = ((self.length & 0xff) << 56) | self.tail;
+
+        v3 ^= last_word;
+        sip_round(&mut v0, &mut v1, &mut v2, &mut v3);
+        v0 ^= last_word;
+
+        v2 ^= 0xff;
+        for _ in 0..3 {
+            sip_round(&mut v0, &mut v1, &mut v2, &mut v3);
+        }
+
+        v0 ^ v1 ^ v2 ^ v3
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_different_instances_have_different_keys() {
+        let a = RandomState::new();
+        let b = RandomState::new();
+
+        assert!(a.k0 != b.k0 || a.k1 != b.k1);
+    }
+
+    #[test]
+    fn test_same_keys_hash_identically() {
+        let state = RandomState::new();
+
+        assert_eq!(state.hash_one("hello"), state.hash_one("hello"));
+    }
+
+    #[test]
+    fn test_different_keys_hash_differently() {
+        let a = RandomState::new();
+        let b = RandomState::new();
+
+        assert_ne!(a.hash_one("hello"), b.hash_one("hello"));
+    }
+
+    #[test]
+    fn test_keys_differ_across_threads() {
+        let main_keys = RandomState::new();
+
+        let spawned_keys = std::thread::spawn(RandomState::new)
+            .join()
+            .expect("spawned thread panicked");
+
+        assert!(main_keys.k0 != spawned_keys.k0 || main_keys.k1 != spawned_keys.k1);
+    }
+
+    #[test]
+    fn test_write_across_multiple_calls_matches_single_call() {
+        let keys = next_keys();
+
+        let mut one_shot = SipHasher13::new_with_keys(keys.0, keys.1);
+        one_shot.write(b"hello, world!");
+
+        let mut piecewise = SipHasher13::new_with_keys(keys.0, keys.1);
+        piecewise.write(b"hello");
+        piecewise.write(b", ");
+        piecewise.write(b"world!");
+
+        assert_eq!(one_shot.finish(), piecewise.finish());
+    }
+}
@@ -0,0 +1,172 @@
+use std::borrow::Borrow;
+use std::hash::{BuildHasher, Hash};
+
+use crate::HashMap;
+
+/// A hash set implemented as a thin wrapper around [`HashMap<T, ()>`](HashMap),
+/// reusing its Robin Hood probing for storage.
+pub struct HashSet<T, S> {
+    map: HashMap<T, (), S>,
+}
+
+impl<T, S> HashSet<T, S>
+where
+    S: BuildHasher,
+    T: Hash + Eq,
+{
+    pub fn new(hash_builder: S) -> Self {
+        Self {
+            map: HashMap::with_hasher(hash_builder),
+        }
+    }
+
+    pub fn with_capacity(capacity: usize, hash_builder: S) -> Self {
+        Self {
+            map: HashMap::with_capacity_and_hasher(capacity, hash_builder),
+        }
+    }
+
+    /// Inserts `value`, returning `true` if it wasn't already present.
+    pub fn insert(&mut self, value: T) -> bool {
+        let is_new = self.map.get(&value).is_none();
+        self.map.insert(value, ());
+        is_new
+    }
+
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(value).is_some()
+    }
+
+    /// Removes `value`, returning whether it was present.
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(value).is_some()
+    }
+
+    /// Returns an iterator over the values in `self` or `other`, without duplicates.
+    pub fn union<'a>(&'a self, other: &'a HashSet<T, S>) -> impl Iterator<Item = &'a T> {
+        self.map
+            .keys()
+            .chain(other.map.keys().filter(move |value| !self.contains(*value)))
+    }
+
+    /// Returns an iterator over the values in both `self` and `other`.
+    pub fn intersection<'a>(&'a self, other: &'a HashSet<T, S>) -> impl Iterator<Item = &'a T> {
+        self.map.keys().filter(move |value| other.contains(*value))
+    }
+
+    /// Returns an iterator over the values in `self` but not in `other`.
+    pub fn difference<'a>(&'a self, other: &'a HashSet<T, S>) -> impl Iterator<Item = &'a T> {
+        self.map.keys().filter(move |value| !other.contains(*value))
+    }
+
+    /// Returns an iterator over the values in `self` or `other`, but not both.
+    pub fn symmetric_difference<'a>(
+        &'a self,
+        other: &'a HashSet<T, S>,
+    ) -> impl Iterator<Item = &'a T> {
+        self.difference(other).chain(other.difference(self))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::BuildHasherDefault;
+
+    fn hasher() -> BuildHasherDefault<DefaultHasher> {
+        BuildHasherDefault::default()
+    }
+
+    #[test]
+    fn test_insert_contains() {
+        let mut set = HashSet::with_capacity(16, hasher());
+
+        assert!(set.insert("a"));
+        assert!(!set.insert("a"));
+        assert!(set.contains("a"));
+        assert!(!set.contains("b"));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = HashSet::with_capacity(16, hasher());
+
+        set.insert("a");
+
+        assert!(set.remove("a"));
+        assert!(!set.remove("a"));
+        assert!(!set.contains("a"));
+    }
+
+    #[test]
+    fn test_union() {
+        let mut a = HashSet::with_capacity(16, hasher());
+        let mut b = HashSet::with_capacity(16, hasher());
+
+        a.insert(1);
+        a.insert(2);
+        b.insert(2);
+        b.insert(3);
+
+        let mut union: Vec<_> = a.union(&b).copied().collect();
+        union.sort_unstable();
+
+        assert_eq!(vec![1, 2, 3], union);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let mut a = HashSet::with_capacity(16, hasher());
+        let mut b = HashSet::with_capacity(16, hasher());
+
+        a.insert(1);
+        a.insert(2);
+        b.insert(2);
+        b.insert(3);
+
+        let intersection: Vec<_> = a.intersection(&b).copied().collect();
+
+        assert_eq!(vec![2], intersection);
+    }
+
+    #[test]
+    fn test_difference() {
+        let mut a = HashSet::with_capacity(16, hasher());
+        let mut b = HashSet::with_capacity(16, hasher());
+
+        a.insert(1);
+        a.insert(2);
+        b.insert(2);
+        b.insert(3);
+
+        let difference: Vec<_> = a.difference(&b).copied().collect();
+
+        assert_eq!(vec![1], difference);
+    }
+
+    #[test]
+    fn test_symmetric_difference() {
+        let mut a = HashSet::with_capacity(16, hasher());
+        let mut b = HashSet::with_capacity(16, hasher());
+
+        a.insert(1);
+        a.insert(2);
+        b.insert(2);
+        b.insert(3);
+
+        let mut symmetric_difference: Vec<_> = a.symmetric_difference(&b).copied().collect();
+        symmetric_difference.sort_unstable();
+
+        assert_eq!(vec![1, 3], symmetric_difference);
+    }
+}